@@ -14,7 +14,7 @@ struct MyApp {
     string: String,
     #[inspect(multiline)]
     code: String,
-    #[inspect(min = 12.0, max = 53.0)]
+    #[inspect(min = 12.0, max = 53.0, description = "An unsigned 32 bit integer")]
     unsigned32: u32,
     #[inspect(hide)]
     _skipped: bool,
@@ -85,6 +85,7 @@ static CUSTOM_BOX: FrameStyle = FrameStyle {
 struct Salut(i32, f32);
 
 #[derive(EguiInspect, PartialEq, Default)]
+#[inspect(summary = "({x}, {y}, {z})")]
 struct Vector {
     #[inspect(name = "X axis")]
     x: f32,
@@ -51,6 +51,11 @@
 //! - `multiline` *(bool)*: If true, display the text on multiple lines (`mut` only)
 //! - `custom_func` *(String)*: Use custom function for non-mut inspect (Evaluate the string as a function path)
 //! - `custom_func_mut` *(String)*: Use custom function for mut inspect (Evaluate the string as a function path)
+//! - `description` *(String)*: Tooltip shown when hovering over the field's widget
+//!
+//! A struct can also carry a `#[inspect(summary = "...")]` attribute, a format
+//! string with `{field}`/`{index}` placeholders rendered as a live summary header instead
+//! of the plain label. On an enum the attribute is accepted but has no effect.
 //!
 
 use egui::{Frame, Margin, Stroke};
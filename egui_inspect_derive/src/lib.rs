@@ -1,16 +1,46 @@
+use std::collections::HashSet;
+
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{
     parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Field, Fields, FieldsNamed,
-    FieldsUnnamed, GenericParam, Generics, Index, Variant,
+    FieldsUnnamed, GenericArgument, GenericParam, Generics, Index, PathArguments, Type, Variant,
 };
 
-use darling::{FromField, FromMeta};
+use darling::{FromDeriveInput, FromField, FromMeta};
 
 mod internal_paths;
+mod summary;
 mod utils;
 
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(inspect), default)]
+struct ContainerAttributeArgs {
+    /// Don't draw the surrounding frame/border for the struct
+    no_border: bool,
+    /// Path to a `FrameStyle` used to draw the surrounding frame
+    style: Option<String>,
+    /// Render the struct inside a collapsible section
+    collapsible: bool,
+    /// Tooltip shown when hovering over the struct's header
+    on_hover_text: Option<String>,
+    /// Format string (`{field}` placeholders) rendered as a live summary header
+    summary: Option<String>,
+}
+
+impl Default for ContainerAttributeArgs {
+    fn default() -> Self {
+        Self {
+            no_border: false,
+            style: None,
+            collapsible: false,
+            on_hover_text: None,
+            summary: None,
+        }
+    }
+}
+
 #[derive(Debug, FromField)]
 #[darling(attributes(inspect), default)]
 struct AttributeArgs {
@@ -32,6 +62,8 @@ struct AttributeArgs {
     custom_func: Option<String>,
     /// Use custom function for mut inspect
     custom_func_mut: Option<String>,
+    /// Tooltip shown when hovering over the field's widget
+    description: Option<String>,
 }
 
 impl Default for AttributeArgs {
@@ -46,6 +78,7 @@ impl Default for AttributeArgs {
             multiline: false,
             custom_func: None,
             custom_func_mut: None,
+            description: None,
         }
     }
 }
@@ -54,14 +87,25 @@ impl Default for AttributeArgs {
 pub fn derive_egui_inspect(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
+    let container_attrs = ContainerAttributeArgs::from_derive_input(&input)
+        .expect("Could not get attributes from struct/enum");
+    let summary = container_attrs.summary.as_ref();
+
     let name = input.ident;
 
-    let generics = add_trait_bounds(input.generics);
+    let generics = add_trait_bounds(input.generics, &input.data, summary);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let inspect = inspect_struct(&input.data, &name, false);
+    let inspect = inspect_struct(&input.data, &name, false, summary);
 
-    let inspect_mut = inspect_struct(&input.data, &name, true);
+    let inspect_mut = inspect_struct(&input.data, &name, true, summary);
+
+    let variant_helpers = match &input.data {
+        Data::Enum(data_enum) => {
+            enum_variant_helpers(data_enum, &name, &impl_generics, &ty_generics, where_clause)
+        }
+        _ => quote!(),
+    };
 
     quote! {
         impl #impl_generics egui_inspect::EguiInspect for #name #ty_generics #where_clause {
@@ -72,24 +116,214 @@ pub fn derive_egui_inspect(input: proc_macro::TokenStream) -> proc_macro::TokenS
                 #inspect_mut
             }
         }
+        #variant_helpers
     }
     .into()
 }
 
-fn add_trait_bounds(mut generics: Generics) -> Generics {
+/// Generates `is_<variant>` predicates and a `variant_name` accessor for an
+/// inspected enum, mirroring the `is_variant` derive pattern so application
+/// code driving the combo-box UI doesn't need hand-written matches.
+fn enum_variant_helpers(
+    data_enum: &DataEnum,
+    name: &Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: Option<&syn::WhereClause>,
+) -> TokenStream {
+    let variants: Vec<_> = data_enum.variants.iter().collect();
+    let name_arms = variants.iter().map(|v| variant_name_arm(v, name));
+    let is_variant_fns = variants.iter().map(|v| is_variant_fn(v));
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn variant_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms,)*
+                }
+            }
+
+            #(#is_variant_fns)*
+        }
+    }
+}
+
+fn is_variant_fn(variant: &Variant) -> TokenStream {
+    let ident = &variant.ident;
+    let method_name = format_ident!("is_{}", to_snake_case(&ident.to_string()));
+    let pattern = match &variant.fields {
+        Fields::Named(_) => quote!(Self::#ident { .. }),
+        Fields::Unnamed(_) => quote!(Self::#ident (..)),
+        Fields::Unit => quote!(Self::#ident),
+    };
+    quote! {
+        pub fn #method_name(&self) -> bool {
+            matches!(self, #pattern)
+        }
+    }
+}
+
+fn to_snake_case(ident: &str) -> String {
+    let mut snake = String::with_capacity(ident.len());
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() && i != 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Adds bounds to each generic param based on how it's actually used: an
+/// `EguiInspect` bound if a field reachable through it emits default inspect
+/// code in `inspect` or `inspect_mut`, and a `Display` bound if it's
+/// reachable through a field the `#[inspect(summary = "...")]` header
+/// references; a param can get both. A param used solely in a hidden field
+/// (such as a hidden `PhantomData<T>` marker) or a field fully handled by
+/// `custom_func`/`custom_func_mut` in both directions is left unbound.
+fn add_trait_bounds(mut generics: Generics, data: &Data, summary: Option<&String>) -> Generics {
+    let param_idents: HashSet<Ident> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => Some(type_param.ident.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let inspect_reachable = reachable_inspect_params(data, &param_idents);
+    let display_reachable = reachable_display_params(data, &param_idents, summary);
+
     for param in &mut generics.params {
         if let GenericParam::Type(ref mut type_param) = *param {
-            type_param
-                .bounds
-                .push(parse_quote!(egui_inspect::EguiInspect));
+            if inspect_reachable.contains(&type_param.ident) {
+                type_param
+                    .bounds
+                    .push(parse_quote!(egui_inspect::EguiInspect));
+            }
+            if display_reachable.contains(&type_param.ident) {
+                type_param.bounds.push(parse_quote!(std::fmt::Display));
+            }
         }
     }
     generics
 }
 
-fn inspect_struct(data: &Data, _struct_name: &Ident, mutable: bool) -> TokenStream {
+/// Collects the generic params reachable from fields referenced by a
+/// `#[inspect(summary = "...")]` header: those need `Display`, since they're
+/// read through `format!`, whether or not the field is also inspected.
+fn reachable_display_params(
+    data: &Data,
+    params: &HashSet<Ident>,
+    summary: Option<&String>,
+) -> HashSet<Ident> {
+    let mut reachable = HashSet::new();
+    let Some(format_str) = summary else {
+        return reachable;
+    };
+    if let Data::Struct(data) = data {
+        // An invalid reference is reported as a compile error by `header_tokens`
+        // when the header itself is generated; here we just skip it.
+        let referenced = summary::referenced_fields(format_str, &data.fields).unwrap_or_default();
+        for (i, field) in data.fields.iter().enumerate() {
+            let field_key = field
+                .ident
+                .as_ref()
+                .map(|ident| ident.to_string())
+                .unwrap_or_else(|| i.to_string());
+            if referenced.contains(&field_key) {
+                collect_reachable_params(&field.ty, params, &mut reachable);
+            }
+        }
+    }
+    reachable
+}
+
+/// Collects the generic params reachable from fields that will emit default
+/// (non-hidden, non-custom-func, non-internal-path) inspect code.
+fn reachable_inspect_params(data: &Data, params: &HashSet<Ident>) -> HashSet<Ident> {
+    let mut reachable = HashSet::new();
+    match data {
+        Data::Struct(data) => collect_reachable_from_fields(&data.fields, params, &mut reachable),
+        Data::Enum(data_enum) => {
+            for variant in &data_enum.variants {
+                collect_reachable_from_fields(&variant.fields, params, &mut reachable);
+            }
+        }
+        Data::Union(_) => {}
+    }
+    reachable
+}
+
+fn collect_reachable_from_fields(fields: &Fields, params: &HashSet<Ident>, reachable: &mut HashSet<Ident>) {
+    for field in fields.iter() {
+        let attr = AttributeArgs::from_field(field).expect("Could not get attributes from field");
+
+        if attr.hide {
+            continue;
+        }
+
+        // Mirrors `handle_custom_func`'s own mutable-aware branching: `custom_func`
+        // always covers the `inspect` direction (and `inspect_mut` too when
+        // `no_edit` is set), while `custom_func_mut` only covers `inspect_mut`.
+        // A field is only fully exempt from the bound if both directions are covered.
+        let immutable_handled = attr.custom_func.is_some();
+        let mutable_handled = if attr.no_edit {
+            attr.custom_func.is_some()
+        } else {
+            attr.custom_func_mut.is_some()
+        };
+
+        if immutable_handled && mutable_handled {
+            continue;
+        }
+
+        if internal_paths::try_handle_internal_path(&field, true, &attr).is_some() {
+            continue;
+        }
+
+        collect_reachable_params(&field.ty, params, reachable);
+    }
+}
+
+fn collect_reachable_params(ty: &Type, params: &HashSet<Ident>, reachable: &mut HashSet<Ident>) {
+    match ty {
+        Type::Path(type_path) => {
+            if let Some(first) = type_path.path.segments.first() {
+                if params.contains(&first.ident) {
+                    reachable.insert(first.ident.clone());
+                }
+            }
+            for segment in &type_path.path.segments {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        if let GenericArgument::Type(inner_ty) = arg {
+                            collect_reachable_params(inner_ty, params, reachable);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(type_ref) => collect_reachable_params(&type_ref.elem, params, reachable),
+        Type::Tuple(type_tuple) => {
+            for elem in &type_tuple.elems {
+                collect_reachable_params(elem, params, reachable);
+            }
+        }
+        Type::Array(type_array) => collect_reachable_params(&type_array.elem, params, reachable),
+        Type::Slice(type_slice) => collect_reachable_params(&type_slice.elem, params, reachable),
+        _ => {}
+    }
+}
+
+fn inspect_struct(
+    data: &Data,
+    _struct_name: &Ident,
+    mutable: bool,
+    summary: Option<&String>,
+) -> TokenStream {
     match *data {
-        Data::Struct(ref data) => handle_fields(&data.fields, mutable),
+        Data::Struct(ref data) => handle_fields(&data.fields, mutable, summary),
         Data::Enum(ref data_enum) => handle_enum(data_enum, _struct_name, mutable),
         Data::Union(_) => unimplemented!("Unions are not yet supported"),
     }
@@ -103,11 +337,11 @@ fn handle_enum(data_enum: &DataEnum, _struct_name: &Ident, mutable: bool) -> Tok
             #(#name_arms,)*
         };
     );
+    let inspect_arms = variants
+        .iter()
+        .map(|v| variant_inspect_arm(v, _struct_name, mutable));
     if mutable {
         let combo_opts = variants.iter().map(|v| variant_combo(v, _struct_name));
-        let inspect_arms = variants
-            .iter()
-            .map(|v| variant_inspect_arm(v, _struct_name));
         quote!(
             #reflect_variant_name
             ui.horizontal(|ui| {
@@ -126,7 +360,9 @@ fn handle_enum(data_enum: &DataEnum, _struct_name: &Ident, mutable: bool) -> Tok
         quote!(
             #reflect_variant_name
             ui.label(format!("{label}: {current_variant}").as_str());
-            // TODO: readonly held data inspect
+            match self {
+                #(#inspect_arms),*
+            };
         )
     }
 }
@@ -170,7 +406,7 @@ fn variant_combo(variant: &Variant, _struct_name: &Ident) -> TokenStream {
     }
 }
 
-fn variant_inspect_arm(variant: &Variant, _struct_name: &Ident) -> TokenStream {
+fn variant_inspect_arm(variant: &Variant, _struct_name: &Ident, mutable: bool) -> TokenStream {
     let ident = &variant.ident;
     match &variant.fields {
         Fields::Named(fields) => {
@@ -187,11 +423,23 @@ fn variant_inspect_arm(variant: &Variant, _struct_name: &Ident) -> TokenStream {
             let inspect_fields = fields
                 .named
                 .iter()
-                .map(|f| handle_named_field(f, true, true));
+                .map(|f| handle_named_field(f, mutable, true));
             quote!(#_struct_name::#ident { #(#field_idents),* } => { #(#inspect_fields;)* })
         }
-        Fields::Unnamed(_) => {
-            unimplemented!("TODO: unnamed")
+        Fields::Unnamed(fields) => {
+            let field_idents: Vec<Ident> = (0..fields.unnamed.len())
+                .map(|i| format_ident!("field_{}", i))
+                .collect();
+            // TODO: properly refer to trait
+            let inspect_fields = field_idents.iter().enumerate().map(|(i, ident)| {
+                let name_str = format!("Field {i}");
+                if mutable {
+                    quote!( #ident.inspect_mut(&#name_str, ui) )
+                } else {
+                    quote!( #ident.inspect(&#name_str, ui) )
+                }
+            });
+            quote!(#_struct_name::#ident ( #(#field_idents),* ) => { #(#inspect_fields;)* })
         }
         Fields::Unit => {
             quote!(#_struct_name::#ident => () )
@@ -199,10 +447,10 @@ fn variant_inspect_arm(variant: &Variant, _struct_name: &Ident) -> TokenStream {
     }
 }
 
-fn handle_fields(fields: &Fields, mutable: bool) -> TokenStream {
+fn handle_fields(fields: &Fields, mutable: bool, summary: Option<&String>) -> TokenStream {
     match fields {
-        Fields::Named(ref fields) => handle_named_fields(fields, mutable),
-        Fields::Unnamed(ref fields) => handle_unnamed_fields(fields, mutable),
+        Fields::Named(ref fields) => handle_named_fields(fields, mutable, summary),
+        Fields::Unnamed(ref fields) => handle_unnamed_fields(fields, mutable, summary),
         // Empty implementation for unit fields (needed in plain enum variant for instance)
         Fields::Unit => quote!(),
     }
@@ -218,28 +466,29 @@ fn handle_named_field(f: &Field, mutable: bool, loose: bool) -> TokenStream {
     let mutable = mutable && !attr.no_edit;
 
     if let Some(ts) = handle_custom_func(&f, mutable, &attr) {
-        return ts;
+        return utils::wrap_with_hover_text(ts, f, &attr.description);
     }
 
     if let Some(ts) = internal_paths::try_handle_internal_path(&f, mutable, &attr) {
-        return ts;
+        return utils::wrap_with_hover_text(ts, f, &attr.description);
     }
 
     return utils::get_default_function_call(&f, mutable, &attr, loose);
 }
 
-fn handle_named_fields(fields: &FieldsNamed, mutable: bool) -> TokenStream {
+fn handle_named_fields(fields: &FieldsNamed, mutable: bool, summary: Option<&String>) -> TokenStream {
     let recurse = fields
         .named
         .iter()
         .map(|f| handle_named_field(f, mutable, false));
+    let header = summary::header_tokens(summary, &Fields::Named(fields.clone()));
     quote! {
-        ui.strong(label);
+        #header
         #(#recurse)*
     }
 }
 
-fn handle_unnamed_fields(fields: &FieldsUnnamed, mutable: bool) -> TokenStream {
+fn handle_unnamed_fields(fields: &FieldsUnnamed, mutable: bool, summary: Option<&String>) -> TokenStream {
     let mut recurse = Vec::new();
     for (i, _) in fields.unnamed.iter().enumerate() {
         let tuple_index = Index::from(i);
@@ -250,8 +499,9 @@ fn handle_unnamed_fields(fields: &FieldsUnnamed, mutable: bool) -> TokenStream {
         );
     }
 
+    let header = summary::header_tokens(summary, &Fields::Unnamed(fields.clone()));
     let result = quote! {
-        ui.strong(label);
+        #header
         #(#recurse)*
     };
     result
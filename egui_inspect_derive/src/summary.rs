@@ -0,0 +1,135 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Fields, Index};
+
+/// Builds the header statement for a struct's `inspect`/`inspect_mut` body.
+///
+/// With no `#[inspect(summary = "...")]` attribute this is just the plain
+/// `label`, matching the previous behaviour. With one, the format string is
+/// rewritten into a `format!(...)` call binding each referenced field, so the
+/// header live-updates as the struct's values change.
+pub(crate) fn header_tokens(summary: Option<&String>, fields: &Fields) -> TokenStream {
+    match summary {
+        None => quote!(ui.strong(label);),
+        Some(format_str) => match build_summary_expr(format_str, fields) {
+            Ok(expr) => quote!(ui.strong(#expr);),
+            Err(err) => err.to_compile_error(),
+        },
+    }
+}
+
+/// Names/indices of the fields referenced by a `summary` format string,
+/// validated against `fields`. Exposed so callers can require `Display`
+/// (rather than `EguiInspect`) on the generic params those fields reach.
+pub(crate) fn referenced_fields(format_str: &str, fields: &Fields) -> syn::Result<Vec<String>> {
+    let names = field_names(fields);
+    let mut referenced = Vec::new();
+    let mut chars = format_str.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '{' => {
+                let placeholder = take_placeholder(&mut chars);
+                if !names.iter().any(|n| n == &placeholder) {
+                    return Err(unknown_field_error(fields, &placeholder));
+                }
+                if !referenced.contains(&placeholder) {
+                    referenced.push(placeholder);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(referenced)
+}
+
+fn unknown_field_error(fields: &Fields, placeholder: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        fields,
+        format!(
+            "#[inspect(summary = \"...\")] references unknown field `{}`",
+            placeholder
+        ),
+    )
+}
+
+fn field_names(fields: &Fields) -> Vec<String> {
+    match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|f| f.ident.as_ref().unwrap().to_string())
+            .collect(),
+        Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len()).map(|i| i.to_string()).collect(),
+        Fields::Unit => Vec::new(),
+    }
+}
+
+fn take_placeholder(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut placeholder = String::new();
+    for c in chars.by_ref() {
+        if c == '}' {
+            break;
+        }
+        placeholder.push(c);
+    }
+    placeholder
+}
+
+fn build_summary_expr(format_str: &str, fields: &Fields) -> syn::Result<TokenStream> {
+    let is_unnamed = matches!(fields, Fields::Unnamed(_));
+    let mut rewritten = String::with_capacity(format_str.len());
+    let mut bindings: Vec<(String, TokenStream)> = Vec::new();
+    let names = field_names(fields);
+    let mut chars = format_str.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                rewritten.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                rewritten.push_str("}}");
+            }
+            '{' => {
+                let placeholder = take_placeholder(&mut chars);
+                if !names.iter().any(|n| n == &placeholder) {
+                    return Err(unknown_field_error(fields, &placeholder));
+                }
+
+                if is_unnamed {
+                    let index: usize = placeholder.parse().unwrap();
+                    let arg_ident = format_ident!("field_{}", index);
+                    let tuple_index = Index::from(index);
+                    rewritten.push('{');
+                    rewritten.push_str(&arg_ident.to_string());
+                    rewritten.push('}');
+                    if !bindings.iter().any(|(n, _)| *n == arg_ident.to_string()) {
+                        bindings.push((arg_ident.to_string(), quote!(self.#tuple_index)));
+                    }
+                } else {
+                    let field_ident = format_ident!("{}", placeholder);
+                    rewritten.push('{');
+                    rewritten.push_str(&placeholder);
+                    rewritten.push('}');
+                    if !bindings.iter().any(|(n, _)| *n == placeholder) {
+                        bindings.push((placeholder, quote!(self.#field_ident)));
+                    }
+                }
+            }
+            other => rewritten.push(other),
+        }
+    }
+
+    let binding_tokens = bindings.into_iter().map(|(name, expr)| {
+        let ident = format_ident!("{}", name);
+        quote!(#ident = #expr)
+    });
+    Ok(quote!(format!(#rewritten #(, #binding_tokens)*)))
+}
@@ -4,7 +4,7 @@ use syn::spanned::Spanned;
 use syn::Type::{Path, Reference};
 use syn::{Field, Type};
 
-use crate::FieldAttr;
+use crate::AttributeArgs;
 
 pub fn get_path_str(type_path: &Type) -> Option<String> {
     match type_path {
@@ -23,7 +23,7 @@ pub fn get_path_str(type_path: &Type) -> Option<String> {
 pub(crate) fn get_default_function_call(
     field: &Field,
     mutable: bool,
-    attrs: &FieldAttr,
+    attrs: &AttributeArgs,
     loose_field: bool,
 ) -> TokenStream {
     let name = &field.ident;
@@ -39,17 +39,36 @@ pub(crate) fn get_default_function_call(
         quote!(self.#name)
     };
 
-    return if mutable {
-        quote_spanned! {field.span() => {
+    let call = if mutable {
+        quote_spanned! {field.span() =>
             // egui_inspect::EguiInspect::inspect_mut(&mut #base, &#name_str, ui);
             #base.inspect_mut(&#name_str, ui);
-            }
         }
     } else {
-        quote_spanned! {field.span() => {
+        quote_spanned! {field.span() =>
             // egui_inspect::EguiInspect::inspect(&#base, &#name_str, ui);
             #base.inspect(&#name_str, ui);
-            }
         }
     };
+
+    wrap_with_hover_text(call, field, &attrs.description)
+}
+
+/// Wraps a generated field-widget statement in `ui.scope` so the call's
+/// response can be captured and given an `on_hover_text` tooltip, since the
+/// widget itself is emitted as a standalone statement rather than an
+/// expression.
+pub(crate) fn wrap_with_hover_text(
+    call: TokenStream,
+    field: &Field,
+    description: &Option<String>,
+) -> TokenStream {
+    match description {
+        Some(description) => quote_spanned! {field.span() => {
+            ui.scope(|ui| { #call }).response.on_hover_text(#description);
+        }},
+        None => quote_spanned! {field.span() => {
+            #call
+        }},
+    }
 }